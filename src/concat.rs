@@ -0,0 +1,144 @@
+//! Schema-checked table concatenation.
+//!
+//! `concat_vertical` stacks two tables that share a row schema `R`, keeping
+//! the first table's header. `concat_horizontal` glues two tables of
+//! differing schemas side by side, row by row, via the same HList `Add`
+//! impls `sugar`'s `AppendToCells`/`AppendToColumns` already lean on.
+use std::ops::Add;
+
+use table::{ColumnDefault, Table};
+
+/// `concat_horizontal` needs a right-hand row for every left-hand row to
+/// glue onto; this is what it returns when the two tables don't have the
+/// same number of rows.
+#[derive(Debug)]
+pub struct RowCountMismatch {
+    pub left_rows: usize,
+    pub right_rows: usize,
+}
+
+/// Pads `defaults` out to `len` entries of `(None, None)`.
+///
+/// `column_defaults` is only as long as its table's header when built via
+/// `ColumnedTable`, and empty otherwise (`HeaderedTable`/`Table::new()`)
+/// — never partial. `concat_horizontal` glues two such vectors end to end,
+/// so each side needs padding to its own header length first, or the
+/// combined vector's positions drift out of step with the combined header.
+fn pad_defaults(mut defaults: Vec<ColumnDefault>, len: usize) -> Vec<ColumnDefault> {
+    while defaults.len() < len {
+        defaults.push((None, None));
+    }
+    defaults
+}
+
+impl<R> Table<R> {
+    /// Stacks `other`'s rows below this table's, keeping this table's
+    /// header. Both tables must share the same row schema `R`, so column
+    /// types are guaranteed to line up at compile time.
+    pub fn concat_vertical(self, other: Table<R>) -> Table<R> {
+        let (header, mut rows, style, column_defaults, mut row_heights) = self.into_parts();
+        let (_, mut other_rows, _, _, mut other_row_heights) = other.into_parts();
+        rows.append(&mut other_rows);
+        row_heights.append(&mut other_row_heights);
+        Table::from_parts(header, rows, style, column_defaults, row_heights)
+    }
+
+    /// Glues `other` onto the right of this table, row by row, producing a
+    /// table whose schema is the concatenation of both row HLists.
+    ///
+    /// Both tables must have the same number of rows; if they don't, this
+    /// returns `Err` rather than silently truncating to the shorter one.
+    pub fn concat_horizontal<R2>(self, other: Table<R2>) -> Result<Table<<R as Add<R2>>::Output>, RowCountMismatch>
+        where R: Add<R2>
+    {
+        let (mut header, rows, style, column_defaults, row_heights) = self.into_parts();
+        let (mut other_header, other_rows, _, other_column_defaults, other_row_heights) = other.into_parts();
+
+        if rows.len() != other_rows.len() {
+            return Err(RowCountMismatch {
+                left_rows: rows.len(),
+                right_rows: other_rows.len(),
+            });
+        }
+
+        let mut column_defaults = pad_defaults(column_defaults, header.len());
+        let mut other_column_defaults = pad_defaults(other_column_defaults, other_header.len());
+        header.append(&mut other_header);
+        column_defaults.append(&mut other_column_defaults);
+        let new_rows: Vec<<R as Add<R2>>::Output> =
+            rows.into_iter().zip(other_rows.into_iter()).map(|(l, r)| l + r).collect();
+        let new_row_heights: Vec<Option<usize>> = row_heights.into_iter()
+            .zip(other_row_heights.into_iter())
+            .map(|(l, r)| match (l, r) {
+                (None, None) => None,
+                (l, r) => Some(::std::cmp::max(l.unwrap_or(1), r.unwrap_or(1))),
+            })
+            .collect();
+
+        Ok(Table::from_parts(header, new_rows, style, column_defaults, new_row_heights))
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use console::Style;
+    use table::{Cell, Column, ColumnedTable, HeaderedTable, Header};
+
+    #[test]
+    fn concat_vertical_appends_rows() {
+        let mut a = HeaderedTable(hlist![Header::<String>("Name".to_string())]);
+        a.add_row(hlist![Cell::new("Joe".to_string())]);
+        let mut b: Table<Hlist![Cell<String>]> = Table::new();
+        b.add_row(hlist![Cell::new("Mary".to_string())]);
+
+        let combined = a.concat_vertical(b);
+        assert_eq!(combined.rows().len(), 2);
+        assert_eq!(combined.header().len(), 1);
+    }
+
+    #[test]
+    fn concat_horizontal_glues_rows_together() {
+        let mut a: Table<Hlist![Cell<String>]> = Table::new();
+        a.add_row(hlist![Cell::new("Joe".to_string())]);
+        a.add_row(hlist![Cell::new("Mary".to_string())]);
+
+        let mut b: Table<Hlist![Cell<usize>]> = Table::new();
+        b.add_row(hlist![Cell::new(10usize)]);
+        b.add_row(hlist![Cell::new(23usize)]);
+
+        let combined = a.concat_horizontal(b).unwrap();
+        assert_eq!(combined.rows().len(), 2);
+    }
+
+    #[test]
+    fn concat_horizontal_keeps_column_defaults_aligned_to_their_own_columns() {
+        let mut a = HeaderedTable(hlist![Header::<String>("Name".to_string()),
+                                          Header::<usize>("Age".to_string())]);
+        a.add_row(hlist![Cell::new("Joe".to_string()), Cell::new(10usize)]);
+
+        let mut b = ColumnedTable(hlist![Column::<bool>("Married".to_string()).style(Style::new().red())]);
+        b.add_row(hlist![Cell::new(true)]);
+
+        let combined = a.concat_horizontal(b).unwrap();
+        assert_eq!(combined.column_defaults().len(), combined.header().len());
+        assert!(combined.column_defaults()[0].0.is_none());
+        assert!(combined.column_defaults()[1].0.is_none());
+        assert!(combined.column_defaults()[2].0.is_some());
+    }
+
+    #[test]
+    fn concat_horizontal_rejects_mismatched_row_counts() {
+        let mut a: Table<Hlist![Cell<String>]> = Table::new();
+        a.add_row(hlist![Cell::new("Joe".to_string())]);
+
+        let b: Table<Hlist![Cell<usize>]> = Table::new();
+
+        match a.concat_horizontal(b) {
+            Err(RowCountMismatch { left_rows: 1, right_rows: 0 }) => {}
+            other => panic!("expected a row count mismatch, got {:?}", other.map(|_| ())),
+        }
+    }
+}
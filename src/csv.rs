@@ -0,0 +1,244 @@
+//! Typed CSV import/export for `Table<R>`.
+//!
+//! Loading a CSV parses each column straight into its `Cell<T>` type instead
+//! of leaving every column as a string (prettytable's `csv` feature only
+//! gives you the untyped version). Writing goes back out through the `csv`
+//! crate so quoting/escaping follows its rules rather than a naive
+//! `join(",")`.
+//!
+//! # Example
+//!
+//! ```
+//! # #[macro_use] extern crate frunk_core;
+//! # extern crate typed_tableau;
+//! # fn main() {
+//! use typed_tableau::*;
+//!
+//! let csv_input = "Name,Age,Married\nJoe,10,false\nMary,23,true\n";
+//! let t: Table<Hlist![Cell<String>, Cell<usize>, Cell<bool>]> =
+//!     FromCsv::from_csv(csv_input.as_bytes()).unwrap();
+//!
+//! let mut out: Vec<u8> = vec![];
+//! t.to_csv(&mut out).unwrap();
+//! # }
+//! ```
+use std::fmt::Display;
+use std::io::{Read, Write};
+use std::str::FromStr;
+use std::vec::IntoIter;
+use frunk_core::hlist::{HCons, HNil};
+use csv_crate;
+
+use table::{Cell, FromHet, Table, UntypedHeader};
+
+/// Something that went wrong turning a CSV record into a typed row.
+#[derive(Debug)]
+pub enum FromCsvError {
+    /// The underlying CSV reader failed (bad encoding, I/O error, ...).
+    Csv(csv_crate::Error),
+    /// A record didn't have the number of columns the schema expects.
+    ColumnCountMismatch {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A field couldn't be parsed into its column's type.
+    Parse {
+        row: usize,
+        col: usize,
+        message: String,
+    },
+}
+
+/// Walks a `Cell<T>` HList column-by-column, parsing each field of a CSV
+/// record with `T::from_str`.
+///
+/// Mirrors the `FromHet` recursion used elsewhere in this crate: `HNil`
+/// consumes no columns, and the `HCons<Cell<H>, T>` case parses the next
+/// column before recursing on the tail.
+pub trait FromCsvRow: Sized {
+    /// Number of CSV columns this schema expects.
+    fn arity() -> usize;
+
+    fn from_csv_row(row: usize, col: usize, fields: &mut IntoIter<String>) -> Result<Self, FromCsvError>;
+}
+
+impl FromCsvRow for HNil {
+    fn arity() -> usize {
+        0
+    }
+
+    fn from_csv_row(_row: usize, _col: usize, _fields: &mut IntoIter<String>) -> Result<Self, FromCsvError> {
+        Ok(HNil)
+    }
+}
+
+impl<H, T> FromCsvRow for HCons<Cell<H>, T>
+    where H: FromStr,
+          T: FromCsvRow
+{
+    fn arity() -> usize {
+        1 + T::arity()
+    }
+
+    fn from_csv_row(row: usize, col: usize, fields: &mut IntoIter<String>) -> Result<Self, FromCsvError> {
+        let raw = fields.next().expect("column count already checked");
+        let val = H::from_str(&raw).map_err(|_| {
+            FromCsvError::Parse {
+                row: row,
+                col: col,
+                message: format!("could not parse {:?}", raw),
+            }
+        })?;
+        let tail = T::from_csv_row(row, col + 1, fields)?;
+        Ok(HCons {
+            head: Cell::new(val),
+            tail: tail,
+        })
+    }
+}
+
+/// Loads a `Table<R>` from CSV, parsing every column into its typed `Cell<T>`.
+pub trait FromCsv: Sized {
+    fn from_csv<Rdr: Read>(reader: Rdr) -> Result<Table<Self>, Vec<FromCsvError>>;
+}
+
+impl<R> FromCsv for R
+    where R: FromCsvRow
+{
+    fn from_csv<Rdr: Read>(reader: Rdr) -> Result<Table<Self>, Vec<FromCsvError>> {
+        let mut rdr = csv_crate::ReaderBuilder::new().flexible(true).from_reader(reader);
+        let expected = R::arity();
+
+        let header: Vec<UntypedHeader> = match rdr.headers() {
+            Ok(names) => names.iter().map(|n| UntypedHeader::from_name(n.to_string())).collect(),
+            Err(e) => return Err(vec![FromCsvError::Csv(e)]),
+        };
+
+        let mut rows = vec![];
+        let mut errors = vec![];
+
+        for (i, result) in rdr.records().enumerate() {
+            match result {
+                Ok(record) => {
+                    if record.len() != expected {
+                        errors.push(FromCsvError::ColumnCountMismatch {
+                            row: i,
+                            expected: expected,
+                            found: record.len(),
+                        });
+                        continue;
+                    }
+                    let fields: Vec<String> = record.iter().map(|s| s.to_string()).collect();
+                    match R::from_csv_row(i, 0, &mut fields.into_iter()) {
+                        Ok(r) => rows.push(r),
+                        Err(e) => errors.push(e),
+                    }
+                }
+                Err(e) => errors.push(FromCsvError::Csv(e)),
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        let mut t = Table::from_parts(header, vec![], None, vec![], vec![]);
+        for r in rows {
+            t.add_row(r);
+        }
+        Ok(t)
+    }
+}
+
+#[doc(hidden)]
+impl FromHet<HNil> for Vec<String> {
+    fn from_het(_: HNil) -> Self {
+        vec![]
+    }
+}
+
+#[doc(hidden)]
+impl<H, T> FromHet<HCons<Cell<H>, T>> for Vec<String>
+    where H: Display,
+          Vec<String>: FromHet<T>
+{
+    fn from_het(a: HCons<Cell<H>, T>) -> Self {
+        let HCons { head: h, tail: t } = a;
+        let mut v_h: Vec<String> = vec![h.val().to_string()];
+        let mut v_t: Vec<String> = FromHet::from_het(t);
+        v_h.append(&mut v_t);
+        v_h
+    }
+}
+
+impl<R> Table<R>
+    where R: Clone,
+          Vec<String>: FromHet<R>
+{
+    /// Writes this table out as CSV: the header row first (only when the
+    /// table has a header), then one record per row.
+    ///
+    /// Reuses the `Display` bound already required of cells and hands
+    /// quoting/escaping off to the `csv` crate.
+    pub fn to_csv<W: Write>(&self, writer: W) -> csv_crate::Result<()> {
+        let mut wtr = csv_crate::Writer::from_writer(writer);
+
+        if !self.header().is_empty() {
+            let names: Vec<&str> = self.header().iter().map(|h| h.name()).collect();
+            wtr.write_record(&names)?;
+        }
+
+        for row in self.rows() {
+            let fields: Vec<String> = FromHet::from_het(row.clone());
+            wtr.write_record(&fields)?;
+        }
+
+        wtr.flush().map_err(csv_crate::Error::from)
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn from_csv_parses_typed_columns() {
+        let input = "Name,Age,Married\nJoe,10,false\nMary,23,true\n";
+        let t: Table<Hlist![Cell<String>, Cell<usize>, Cell<bool>]> =
+            FromCsv::from_csv(input.as_bytes()).unwrap();
+        assert_eq!(t.rows().len(), 2);
+        assert_eq!(t.header().iter().map(|h| h.name()).collect::<Vec<_>>(),
+                   vec!["Name", "Age", "Married"]);
+    }
+
+    #[test]
+    fn from_csv_reports_column_count_mismatch() {
+        let input = "Name,Age,Married\nJoe,10\n";
+        let result: Result<Table<Hlist![Cell<String>, Cell<usize>, Cell<bool>]>, _> =
+            FromCsv::from_csv(input.as_bytes());
+        match result {
+            Err(errors) => {
+                assert_eq!(errors.len(), 1);
+                match errors[0] {
+                    FromCsvError::ColumnCountMismatch { row: 0, expected: 3, found: 2 } => {}
+                    ref other => panic!("unexpected error: {:?}", other),
+                }
+            }
+            Ok(_) => panic!("expected a column count mismatch"),
+        }
+    }
+
+    #[test]
+    fn to_csv_round_trips() {
+        let input = "Name,Age,Married\nJoe,10,false\nMary,23,true\n";
+        let t: Table<Hlist![Cell<String>, Cell<usize>, Cell<bool>]> =
+            FromCsv::from_csv(input.as_bytes()).unwrap();
+
+        let mut out: Vec<u8> = vec![];
+        t.to_csv(&mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), input);
+    }
+}
@@ -45,9 +45,21 @@
 extern crate frunk_core;
 extern crate console;
 extern crate tableau;
+extern crate csv as csv_crate;
+extern crate typed_tableau_derive;
 
 pub use tableau::TableStyle;
+pub use typed_tableau_derive::TypedRow;
 
 pub mod table;
+pub mod sugar;
+pub mod csv;
+pub mod typed_row;
+pub mod select;
+pub mod concat;
 
 pub use table::*;
+pub use sugar::*;
+pub use csv::*;
+pub use typed_row::*;
+pub use concat::*;
@@ -0,0 +1,173 @@
+//! Type-level column projection.
+//!
+//! `select` carves a `Table<R>` down to a `Table<S>` whose schema `S` is
+//! exactly the chosen subset/reordering of `R`'s columns, checked at
+//! compile time via frunk's `Sculptor`. `complement` gives back the columns
+//! that were left out, so a table can be split into two typed halves.
+//!
+//! `Sculptor` selects by type, so projection is ambiguous when two columns
+//! share a type (e.g. two `Cell<String>` columns) — reach for a newtype
+//! wrapper around one of them if that's your situation.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let name_and_married = table.select::<Hlist![Cell<String>, Cell<bool>], _>();
+//! ```
+use std::marker::PhantomData;
+use frunk_core::hlist::{HCons, HNil, Sculptor};
+
+use table::{Cell, Table};
+
+/// A stand-in for `Cell<T>` that carries its original column's
+/// runtime index instead of a value.
+///
+/// `select`/`complement` run the same `Sculptor`-driven selection over a
+/// `ColMarkers`-built HList of these, in lockstep with the real rows, so the
+/// (type-erased) header vector can be reordered to match.
+#[doc(hidden)]
+pub struct ColMarker<T>(usize, PhantomData<T>);
+
+#[doc(hidden)]
+pub trait ColMarkers {
+    type Out;
+    fn col_markers(start: usize) -> Self::Out;
+}
+
+impl ColMarkers for HNil {
+    type Out = HNil;
+
+    fn col_markers(_start: usize) -> Self::Out {
+        HNil
+    }
+}
+
+impl<H, T> ColMarkers for HCons<Cell<H>, T>
+    where T: ColMarkers
+{
+    type Out = HCons<ColMarker<H>, <T as ColMarkers>::Out>;
+
+    fn col_markers(start: usize) -> Self::Out {
+        HCons {
+            head: ColMarker(start, PhantomData),
+            tail: T::col_markers(start + 1),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub trait CollectIndices {
+    fn collect_indices(self, out: &mut Vec<usize>);
+}
+
+impl CollectIndices for HNil {
+    fn collect_indices(self, _out: &mut Vec<usize>) {}
+}
+
+impl<H, T> CollectIndices for HCons<ColMarker<H>, T>
+    where T: CollectIndices
+{
+    fn collect_indices(self, out: &mut Vec<usize>) {
+        out.push(self.head.0);
+        self.tail.collect_indices(out);
+    }
+}
+
+/// Projects `items` (the header or column defaults) down to `indices`.
+///
+/// Per table, both of these are either empty or exactly `header.len()` long
+/// — `Table::new()`/plain `add_row` leave the header empty, and only
+/// `ColumnedTable` populates `column_defaults` — so an empty `items` means
+/// there's nothing to project rather than a real index into it.
+fn pick<T: Clone>(items: &[T], indices: &[usize]) -> Vec<T> {
+    if items.is_empty() {
+        return vec![];
+    }
+    indices.iter().map(|&i| items[i].clone()).collect()
+}
+
+impl<R> Table<R>
+    where R: ColMarkers
+{
+    /// Projects onto a chosen subset/reordering of columns, returning a
+    /// `Table<S>` whose schema is exactly the selected columns.
+    pub fn select<S, Indices>(self) -> Table<S>
+        where R: Sculptor<S, Indices>,
+              S: ColMarkers,
+              <R as ColMarkers>::Out: Sculptor<<S as ColMarkers>::Out, Indices>,
+              <S as ColMarkers>::Out: CollectIndices
+    {
+        let (selected_markers, _): (<S as ColMarkers>::Out, _) = R::col_markers(0).sculpt();
+        let mut indices = vec![];
+        selected_markers.collect_indices(&mut indices);
+
+        let (header, rows, style, column_defaults, row_heights) = self.into_parts();
+        let new_header = pick(&header, &indices);
+        let new_defaults = pick(&column_defaults, &indices);
+        let new_rows: Vec<S> = rows.into_iter().map(|r| r.sculpt().0).collect();
+
+        Table::from_parts(new_header, new_rows, style, new_defaults, row_heights)
+    }
+
+    /// The columns left over after selecting `S` out of this table's schema
+    /// — the other half of the split `select::<S, _>()` produces.
+    pub fn complement<S, Indices>(self) -> Table<<R as Sculptor<S, Indices>>::Remainder>
+        where R: Sculptor<S, Indices>,
+              S: ColMarkers,
+              <R as ColMarkers>::Out: Sculptor<<S as ColMarkers>::Out, Indices>,
+              <<R as ColMarkers>::Out as Sculptor<<S as ColMarkers>::Out, Indices>>::Remainder: CollectIndices
+    {
+        let (_, remainder_markers): (<S as ColMarkers>::Out, _) = R::col_markers(0).sculpt();
+        let mut indices = vec![];
+        remainder_markers.collect_indices(&mut indices);
+
+        let (header, rows, style, column_defaults, row_heights) = self.into_parts();
+        let new_header = pick(&header, &indices);
+        let new_defaults = pick(&column_defaults, &indices);
+        let new_rows = rows.into_iter().map(|r| r.sculpt().1).collect();
+
+        Table::from_parts(new_header, new_rows, style, new_defaults, row_heights)
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use table::{Header, HeaderedTable};
+
+    #[test]
+    fn select_on_a_table_with_no_header_does_not_panic() {
+        let mut t: Table<Hlist![Cell<String>, Cell<usize>, Cell<bool>]> = Table::new();
+        t.add_row(hlist![Cell::new("Joe".to_string()), Cell::new(10usize), Cell::new(false)]);
+
+        let selected = t.select::<Hlist![Cell<String>, Cell<bool>], _>();
+        assert_eq!(selected.header().len(), 0);
+        assert_eq!(selected.rows().len(), 1);
+    }
+
+    #[test]
+    fn select_on_a_headered_table_does_not_panic() {
+        let mut t = HeaderedTable(hlist![Header::<String>("Name".to_string()),
+                                          Header::<usize>("Age".to_string()),
+                                          Header::<bool>("Married".to_string())]);
+        t.add_row(hlist![Cell::new("Joe".to_string()), Cell::new(10usize), Cell::new(false)]);
+
+        let selected = t.select::<Hlist![Cell<String>, Cell<bool>], _>();
+        assert_eq!(selected.header().len(), 2);
+        assert_eq!(selected.rows().len(), 1);
+    }
+
+    #[test]
+    fn complement_on_a_headered_table_does_not_panic() {
+        let mut t = HeaderedTable(hlist![Header::<String>("Name".to_string()),
+                                          Header::<usize>("Age".to_string()),
+                                          Header::<bool>("Married".to_string())]);
+        t.add_row(hlist![Cell::new("Joe".to_string()), Cell::new(10usize), Cell::new(false)]);
+
+        let rest = t.complement::<Hlist![Cell<String>, Cell<bool>], _>();
+        assert_eq!(rest.header().len(), 1);
+        assert_eq!(rest.rows().len(), 1);
+    }
+}
@@ -36,13 +36,15 @@ use std::fmt::Display;
 use tableau;
 use console::{Style, Alignment};
 use std::marker::PhantomData;
-use frunk_core::hlist::{HCons, HNil};
+use frunk_core::hlist::{HCons, HNil, Selector};
 
 pub struct Table<R> {
     #[doc(hidden)]
     header: Vec<UntypedHeader>,
     rows: Vec<R>,
     style: Option<tableau::TableStyle>,
+    column_defaults: Vec<ColumnDefault>,
+    row_heights: Vec<Option<usize>>,
 }
 
 /// Creates a table with the given column headers.
@@ -71,6 +73,45 @@ pub fn HeaderedTable<H>(header: H) -> Table<<H as CellTypeExtractor>::Out>
         header: as_headers,
         rows: vec![],
         style: None,
+        column_defaults: vec![],
+        row_heights: vec![],
+    }
+}
+
+/// Creates a table from columns carrying default style/alignment, alongside
+/// their header names.
+///
+/// Any `Cell<T>` added to the table that doesn't set its own style or
+/// alignment falls back to its owning column's default when rendered.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate frunk_core;
+/// # extern crate typed_tableau;
+/// # extern crate console;
+/// # use typed_tableau::*;
+/// # fn main() {
+/// use console::Style;
+/// let t = ColumnedTable(hlist![
+///     Column::<String>("Name".to_string()),
+///     Column::<bool>("Married".to_string()).style(Style::new().green())
+/// ]);
+/// # }
+/// ```
+#[allow(non_snake_case)]
+pub fn ColumnedTable<H>(columns: H) -> Table<<H as CellTypeExtractor>::Out>
+    where H: CellTypeExtractor + IntoColumnDefaults + Clone,
+          Vec<UntypedHeader>: FromHet<H>
+{
+    let column_defaults = columns.clone().into_column_defaults();
+    let as_headers = FromHet::from_het(columns);
+    Table {
+        header: as_headers,
+        rows: vec![],
+        style: None,
+        column_defaults: column_defaults,
+        row_heights: vec![],
     }
 }
 
@@ -81,6 +122,8 @@ impl<R> Table<R> {
             header: vec![],
             rows: vec![],
             style: None,
+            column_defaults: vec![],
+            row_heights: vec![],
         }
     }
 
@@ -88,17 +131,85 @@ impl<R> Table<R> {
         self.style = Some(style);
     }
 
+    #[doc(hidden)]
+    pub fn header(&self) -> &[UntypedHeader] {
+        &self.header
+    }
+
+    #[doc(hidden)]
+    pub fn rows(&self) -> &[R] {
+        &self.rows
+    }
+
+    #[doc(hidden)]
+    pub fn column_defaults(&self) -> &[ColumnDefault] {
+        &self.column_defaults
+    }
+
+    #[doc(hidden)]
+    pub fn from_parts(header: Vec<UntypedHeader>,
+                       rows: Vec<R>,
+                       style: Option<tableau::TableStyle>,
+                       column_defaults: Vec<ColumnDefault>,
+                       row_heights: Vec<Option<usize>>)
+                       -> Table<R> {
+        Table {
+            header: header,
+            rows: rows,
+            style: style,
+            column_defaults: column_defaults,
+            row_heights: row_heights,
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn into_parts(self)
+                       -> (Vec<UntypedHeader>, Vec<R>, Option<tableau::TableStyle>, Vec<ColumnDefault>, Vec<Option<usize>>) {
+        (self.header, self.rows, self.style, self.column_defaults, self.row_heights)
+    }
+
     /// Adds a typed row to our table
     pub fn add_row<NewR>(&mut self, new_row: NewR)
         where R: From<NewR>
     {
         let as_r = R::from(new_row);
         self.rows.push(as_r);
+        self.row_heights.push(None);
+    }
+
+    /// Adds a typed row to our table, requesting at least `height` lines of
+    /// vertical room for it so multi-line cell content isn't clipped.
+    pub fn add_row_with_height<NewR>(&mut self, new_row: NewR, height: usize)
+        where R: From<NewR>
+    {
+        let as_r = R::from(new_row);
+        self.rows.push(as_r);
+        self.row_heights.push(Some(height));
+    }
+
+    /// Returns the value of the statically-selected column `T` in row
+    /// `row_idx`, or `None` if there's no such row.
+    ///
+    /// Selects by type via frunk's `Selector`, so this is ambiguous when two
+    /// columns share a type (e.g. two `String` columns) — reach for a
+    /// newtype wrapper around one of them if that's your situation.
+    pub fn get<T, Index>(&self, row_idx: usize) -> Option<&T>
+        where R: Selector<Cell<T>, Index>
+    {
+        self.rows.get(row_idx).map(|r| &Selector::<Cell<T>, Index>::get(r).val)
+    }
+
+    /// All values in the statically-selected column `T`, one per row, in
+    /// row order.
+    pub fn column_values<T, Index>(&self) -> Vec<&T>
+        where R: Selector<Cell<T>, Index>
+    {
+        self.rows.iter().map(|r| &Selector::<Cell<T>, Index>::get(r).val).collect()
     }
 
     /// Returns an untyped Tableau table from our typed table
     pub fn into_untyped(self) -> tableau::Table
-        where Vec<tableau::Cell>: FromHet<R>
+        where R: IntoStyledCells
     {
         let mut tableau_table = tableau::Table::new();
 
@@ -110,9 +221,13 @@ impl<R> Table<R> {
             }
         }
 
-        for r in self.rows {
+        let column_defaults = self.column_defaults;
+        for (r, height) in self.rows.into_iter().zip(self.row_heights.into_iter()) {
             let mut u_row = tableau_table.add_row();
-            let t_row: Vec<tableau::Cell> = FromHet::from_het(r);
+            if let Some(height) = height {
+                u_row.set_height(height);
+            }
+            let t_row: Vec<tableau::Cell> = r.into_styled_cells(&column_defaults);
             for ut_cell in t_row {
                 u_row.add_cell(ut_cell);
             }
@@ -122,6 +237,7 @@ impl<R> Table<R> {
     }
 }
 
+#[derive(Clone)]
 pub struct Cell<C> {
     val: C,
     style: Option<Style>,
@@ -146,6 +262,32 @@ impl<C> Cell<C> {
         self.style = Some(style);
         self
     }
+
+    /// Not part of the public API; exposed so sibling modules (e.g. `csv`)
+    /// can get at a cell's value without reaching into a private field.
+    #[doc(hidden)]
+    pub fn val(&self) -> &C {
+        &self.val
+    }
+}
+
+impl<C> Cell<C>
+    where C: Display
+{
+    /// Number of lines this cell's content spans, honoring embedded `\n`s
+    /// in its `Display` output. Handy for picking a row height that won't
+    /// clip a multi-line cell.
+    pub fn lines(&self) -> usize {
+        let rendered = self.val.to_string();
+        let n = rendered.lines().count();
+        if n == 0 { 1 } else { n }
+    }
+}
+
+/// Builds a `Cell`. Sugar for `Cell::new` so callers chaining `sugar`'s
+/// `AppendToCells`/`AppendWith` don't have to write `Cell::new` everywhere.
+pub fn cell<C>(v: C) -> Cell<C> {
+    Cell::new(v)
 }
 
 
@@ -196,6 +338,139 @@ pub fn Header<T>(s: String) -> Header<T> {
     }
 }
 
+/// A column's default style/alignment, consulted by `into_untyped` when one
+/// of its cells doesn't set its own.
+pub type ColumnDefault = (Option<Style>, Option<Alignment>);
+
+/// A typed column.
+///
+/// Like `Header`, a `Column` names a column and styles its header cell;
+/// unlike `Header`, its style/alignment also serve as the default for every
+/// `Cell` in that column (via `ColumnedTable`), so a whole column can be
+/// styled once instead of restyling every `Cell` in it.
+pub struct Column<H> {
+    pub name: String,
+    pub style: Option<Style>,
+    pub alignment: Option<Alignment>,
+    tp_holder: PhantomData<H>,
+}
+
+impl<H> Column<H> {
+    pub fn align(mut self, alignment: Alignment) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+}
+
+impl<H> Clone for Column<H> {
+    fn clone(&self) -> Self {
+        Column {
+            name: self.name.clone(),
+            style: self.style.clone(),
+            alignment: self.alignment,
+            tp_holder: PhantomData,
+        }
+    }
+}
+
+/// Builds a column with the given type and name
+#[allow(non_snake_case)]
+pub fn Column<T>(s: String) -> Column<T> {
+    Column {
+        name: s,
+        style: None,
+        alignment: None,
+        tp_holder: PhantomData,
+    }
+}
+
+/// Builds a column with the given type and name. Sugar for `Column` so
+/// callers chaining `sugar`'s `AppendToColumns`/`AppendWith` don't have to
+/// write `.to_string()` on every name.
+pub fn column<T>(name: &str) -> Column<T> {
+    Column(name.to_string())
+}
+
+#[doc(hidden)]
+impl<H> From<Column<H>> for UntypedHeader {
+    fn from(t: Column<H>) -> Self {
+        UntypedHeader {
+            name: t.name,
+            style: t.style,
+            alignment: t.alignment,
+        }
+    }
+}
+
+/// Given a `Column<T>` HList, extracts each column's default style and
+/// alignment in order.
+#[doc(hidden)]
+pub trait IntoColumnDefaults {
+    fn into_column_defaults(self) -> Vec<ColumnDefault>;
+}
+
+impl IntoColumnDefaults for HNil {
+    fn into_column_defaults(self) -> Vec<ColumnDefault> {
+        vec![]
+    }
+}
+
+impl<H, T> IntoColumnDefaults for HCons<Column<H>, T>
+    where T: IntoColumnDefaults
+{
+    fn into_column_defaults(self) -> Vec<ColumnDefault> {
+        let HCons { head, tail } = self;
+        let mut defaults = vec![(head.style, head.alignment)];
+        defaults.append(&mut tail.into_column_defaults());
+        defaults
+    }
+}
+
+/// Converts a `Cell<T>` HList into `tableau::Cell`s, falling back to the
+/// owning column's default style/alignment (by position) whenever a cell
+/// doesn't set its own.
+#[doc(hidden)]
+pub trait IntoStyledCells {
+    fn into_styled_cells(self, column_defaults: &[ColumnDefault]) -> Vec<tableau::Cell>;
+}
+
+impl IntoStyledCells for HNil {
+    fn into_styled_cells(self, _column_defaults: &[ColumnDefault]) -> Vec<tableau::Cell> {
+        vec![]
+    }
+}
+
+impl<H, T> IntoStyledCells for HCons<Cell<H>, T>
+    where H: Display,
+          T: IntoStyledCells
+{
+    fn into_styled_cells(self, column_defaults: &[ColumnDefault]) -> Vec<tableau::Cell> {
+        let HCons { head, tail } = self;
+        let (default_style, default_alignment) = column_defaults.get(0)
+            .cloned()
+            .unwrap_or((None, None));
+        let styled = Cell {
+            val: head.val,
+            style: head.style.or(default_style),
+            alignment: head.alignment.or(default_alignment),
+        };
+
+        let mut cells = vec![tableau::Cell::from(styled)];
+        let rest = if column_defaults.len() > 1 {
+            &column_defaults[1..]
+        } else {
+            &[]
+        };
+        cells.append(&mut tail.into_styled_cells(rest));
+        cells
+    }
+}
+
 /// Given any type, produces an Out type.
 ///
 /// Used for converting a HList of Header<T> into an HList of
@@ -214,6 +489,12 @@ impl<H, T> CellTypeExtractor for HCons<Header<H>, T>
     type Out = HCons<Cell<H>, <T as CellTypeExtractor>::Out>;
 }
 
+impl<H, T> CellTypeExtractor for HCons<Column<H>, T>
+    where T: CellTypeExtractor
+{
+    type Out = HCons<Cell<H>, <T as CellTypeExtractor>::Out>;
+}
+
 impl From<UntypedHeader> for tableau::Cell {
     fn from(h: UntypedHeader) -> Self {
         let styled = match h.style {
@@ -229,12 +510,32 @@ impl From<UntypedHeader> for tableau::Cell {
 }
 
 #[doc(hidden)]
+#[derive(Clone)]
 pub struct UntypedHeader {
     name: String,
     style: Option<Style>,
     alignment: Option<Alignment>,
 }
 
+impl UntypedHeader {
+    #[doc(hidden)]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Not part of the public API; exposed so sibling modules (e.g. `csv`)
+    /// can build a header entry that isn't carrying a `Header<T>`/`Column<T>`
+    /// (e.g. a column name read back from a data source at runtime).
+    #[doc(hidden)]
+    pub fn from_name(name: String) -> UntypedHeader {
+        UntypedHeader {
+            name: name,
+            style: None,
+            alignment: None,
+        }
+    }
+}
+
 #[doc(hidden)]
 impl<H> From<Header<H>> for UntypedHeader {
     fn from(t: Header<H>) -> Self {
@@ -336,4 +637,38 @@ mod tests {
         t.into_untyped();
     }
 
+    #[test]
+    fn columned_table_falls_back_to_column_style() {
+        let mut t = ColumnedTable(hlist![Column::<String>("Name".to_string()),
+                                         Column::<bool>("Married".to_string()).style(Style::new().green())]);
+        t.add_row(hlist![Cell::new("Joe".to_string()), Cell::new(false)]);
+        t.add_row(hlist![Cell::new("Mary".to_string()).style(Style::new().red()), Cell::new(true)]);
+        t.into_untyped();
+    }
+
+    #[test]
+    fn cell_lines_counts_embedded_newlines() {
+        assert_eq!(Cell::new("one line").lines(), 1);
+        assert_eq!(Cell::new("two\nlines").lines(), 2);
+    }
+
+    #[test]
+    fn add_row_with_height_renders() {
+        let mut t: Table<Hlist![Cell<String>]> = Table::new();
+        t.add_row(hlist![Cell::new("short".to_string())]);
+        t.add_row_with_height(hlist![Cell::new("one\ntwo\nthree".to_string())], 3);
+        t.into_untyped();
+    }
+
+    #[test]
+    fn get_and_column_values_select_by_type() {
+        let mut t: Table<Hlist![Cell<String>, Cell<usize>, Cell<bool>]> = Table::new();
+        t.add_row(hlist![Cell::new("Joe".to_string()), Cell::new(10usize), Cell::new(false)]);
+        t.add_row(hlist![Cell::new("Mary".to_string()), Cell::new(23usize), Cell::new(true)]);
+
+        assert_eq!(t.get::<String, _>(1), Some(&"Mary".to_string()));
+        assert_eq!(t.get::<String, _>(2), None);
+        assert_eq!(t.column_values::<usize, _>(), vec![&10usize, &23usize]);
+    }
+
 }
@@ -0,0 +1,61 @@
+//! Building a `Table<R>` straight from a `Vec` of domain structs.
+//!
+//! Hand-writing the header `hlist!` and every row `hlist!` is tedious and
+//! error-prone, so `#[derive(TypedRow)]` generates both for you. See the
+//! `typed_tableau_derive` crate for the macro itself; this module only
+//! holds the trait it implements and the `Table` constructor that uses it.
+//!
+//! # Example
+//!
+//! ```ignore
+//! #[derive(TypedRow)]
+//! struct Person {
+//!     #[typed_row(rename = "Full Name")]
+//!     name: String,
+//!     age: usize,
+//!     married: bool,
+//! }
+//!
+//! let t = from_rows(vec![
+//!     Person { name: "Joe".to_string(), age: 10, married: false },
+//!     Person { name: "Mary".to_string(), age: 23, married: true },
+//! ]);
+//! ```
+use table::{CellTypeExtractor, FromHet, HeaderedTable, Table, UntypedHeader};
+
+/// Implemented by `#[derive(TypedRow)]` structs.
+///
+/// `Header` is the HList of `Header<T>` matching the struct's (reorderable,
+/// skippable, renameable) fields; `typed_header()` builds it. `into_cells()`
+/// converts a value into the matching `Cell<T>` HList.
+///
+/// This is a dedicated method rather than `impl From<Self> for
+/// <Header as CellTypeExtractor>::Out`: coherence can't rule out
+/// `<Header as CellTypeExtractor>::Out` being `Self`, so that `From` impl
+/// conflicts with core's blanket `impl<T> From<T> for T` for every type.
+pub trait TypedRow: Sized {
+    type Header: CellTypeExtractor;
+
+    fn typed_header() -> Self::Header;
+    fn into_cells(self) -> <Self::Header as CellTypeExtractor>::Out;
+}
+
+/// Builds a headered table from an iterator of `#[derive(TypedRow)]` values,
+/// using the generated header and `Cell` conversion.
+///
+/// A free function rather than a `Table` method for the same reason
+/// `HeaderedTable`/`ColumnedTable` are: the result's row type is a
+/// projection (`T::Header::Out`) of the type parameter, which an inherent
+/// impl can't use to constrain `Table<R>`'s own `R`.
+#[allow(non_snake_case)]
+pub fn from_rows<T, I>(rows: I) -> Table<<T::Header as CellTypeExtractor>::Out>
+    where T: TypedRow,
+          Vec<UntypedHeader>: FromHet<T::Header>,
+          I: IntoIterator<Item = T>
+{
+    let mut t = HeaderedTable(T::typed_header());
+    for row in rows {
+        t.add_row(row.into_cells());
+    }
+    t
+}
@@ -0,0 +1,57 @@
+//! Exercises `#[derive(TypedRow)]` end to end. Lives here rather than in
+//! `src/typed_row.rs`'s own `#[cfg(test)]` module because the derive can
+//! only be used from a crate that depends on `typed_tableau` itself.
+#[macro_use]
+extern crate frunk_core;
+extern crate typed_tableau;
+
+use typed_tableau::*;
+
+#[derive(TypedRow)]
+struct Person {
+    #[typed_row(rename = "Full Name")]
+    name: String,
+    age: usize,
+    married: bool,
+}
+
+#[test]
+fn derive_builds_a_headered_table_from_rows() {
+    let t = from_rows(vec![Person {
+                                name: "Joe".to_string(),
+                                age: 10,
+                                married: false,
+                            },
+                            Person {
+                                name: "Mary".to_string(),
+                                age: 23,
+                                married: true,
+                            }]);
+
+    assert_eq!(t.header().iter().map(|h| h.name()).collect::<Vec<_>>(),
+               vec!["Full Name", "age", "married"]);
+    assert_eq!(t.rows().len(), 2);
+}
+
+#[derive(TypedRow)]
+struct Ordered {
+    #[typed_row(order = 1)]
+    second: usize,
+    #[typed_row(order = 0)]
+    first: String,
+    #[typed_row(skip)]
+    #[allow(unused)]
+    ignored: bool,
+}
+
+#[test]
+fn derive_honors_order_and_skip() {
+    let t = from_rows(vec![Ordered {
+                                second: 2,
+                                first: "one".to_string(),
+                                ignored: true,
+                            }]);
+
+    assert_eq!(t.header().iter().map(|h| h.name()).collect::<Vec<_>>(),
+               vec!["first", "second"]);
+}
@@ -0,0 +1,145 @@
+//! `#[derive(TypedRow)]`, the companion macro to `typed_tableau`.
+//!
+//! For a struct such as
+//!
+//! ```ignore
+//! #[derive(TypedRow)]
+//! struct Person {
+//!     #[typed_row(rename = "Full Name")]
+//!     name: String,
+//!     age: usize,
+//!     married: bool,
+//! }
+//! ```
+//!
+//! this generates the header `HList` (`Header::<String>("Full Name"),
+//! Header::<usize>("age"), Header::<bool>("married")`) and an
+//! `into_cells()` producing the matching `Cell<T>` HList, in declared field
+//! order unless overridden by `#[typed_row(order = N)]`.
+//! `#[typed_row(skip)]` drops a field from both.
+extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use]
+extern crate quote;
+extern crate syn;
+
+use proc_macro::TokenStream;
+use syn::{Data, DeriveInput, Fields, Ident, Lit, Meta, NestedMeta};
+
+#[proc_macro_derive(TypedRow, attributes(typed_row))]
+pub fn derive_typed_row(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("#[derive(TypedRow)]: failed to parse input");
+    let name = &input.ident;
+
+    let fields = match input.data {
+        Data::Struct(ref data) => {
+            match data.fields {
+                Fields::Named(ref fields) => &fields.named,
+                _ => panic!("#[derive(TypedRow)] only supports structs with named fields"),
+            }
+        }
+        _ => panic!("#[derive(TypedRow)] only supports structs"),
+    };
+
+    let mut columns: Vec<Column> = fields.iter()
+        .enumerate()
+        .filter_map(|(i, f)| Column::from_field(i, f))
+        .collect();
+    columns.sort_by_key(|c| c.order);
+
+    let header_ty = columns.iter().rev().fold(quote! { ::frunk_core::hlist::HNil }, |acc, c| {
+        let ty = &c.ty;
+        quote! { ::frunk_core::hlist::HCons<::typed_tableau::Header<#ty>, #acc> }
+    });
+
+    let header_expr = columns.iter().rev().fold(quote! { ::frunk_core::hlist::HNil }, |acc, c| {
+        let ty = &c.ty;
+        let header_name = &c.header_name;
+        quote! {
+            ::frunk_core::hlist::HCons {
+                head: ::typed_tableau::Header::<#ty>(#header_name.to_string()),
+                tail: #acc,
+            }
+        }
+    });
+
+    let row_expr = columns.iter().rev().fold(quote! { ::frunk_core::hlist::HNil }, |acc, c| {
+        let field_ident = &c.ident;
+        quote! {
+            ::frunk_core::hlist::HCons {
+                head: ::typed_tableau::Cell::new(v.#field_ident),
+                tail: #acc,
+            }
+        }
+    });
+
+    let expanded = quote! {
+        impl ::typed_tableau::TypedRow for #name {
+            type Header = #header_ty;
+
+            fn typed_header() -> Self::Header {
+                #header_expr
+            }
+
+            fn into_cells(self) -> <Self::Header as ::typed_tableau::CellTypeExtractor>::Out {
+                let v = self;
+                #row_expr
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+struct Column {
+    ident: Ident,
+    ty: syn::Type,
+    order: usize,
+    header_name: String,
+}
+
+impl Column {
+    fn from_field(position: usize, field: &syn::Field) -> Option<Column> {
+        let ident = field.ident.clone().expect("#[derive(TypedRow)] requires named fields");
+        let mut order = position;
+        let mut header_name = ident.to_string();
+        let mut skip = false;
+
+        for attr in &field.attrs {
+            if !attr.path.is_ident("typed_row") {
+                continue;
+            }
+            if let Ok(Meta::List(list)) = attr.parse_meta() {
+                for nested in list.nested.iter() {
+                    match nested {
+                        NestedMeta::Meta(Meta::NameValue(nv)) if nv.ident == "rename" => {
+                            if let Lit::Str(ref s) = nv.lit {
+                                header_name = s.value();
+                            }
+                        }
+                        NestedMeta::Meta(Meta::NameValue(nv)) if nv.ident == "order" => {
+                            if let Lit::Int(ref i) = nv.lit {
+                                order = i.value() as usize;
+                            }
+                        }
+                        NestedMeta::Meta(Meta::Word(ref w)) if w == "skip" => {
+                            skip = true;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        if skip {
+            return None;
+        }
+
+        Some(Column {
+            ident: ident,
+            ty: field.ty.clone(),
+            order: order,
+            header_name: header_name,
+        })
+    }
+}